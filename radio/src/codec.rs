@@ -0,0 +1,67 @@
+/// Transforms payload bytes before they're framed for transmission and after a
+/// frame is reassembled on receive, analogous to how lonelyradio layers codecs
+/// around its transport. Implementations may be stateful across calls (e.g. a
+/// keystream cipher), which is why `encode`/`decode` take `&mut self`.
+pub trait Codec: Send {
+    fn encode(&mut self, data: &[u8]) -> Vec<u8>;
+    fn decode(&mut self, data: &[u8]) -> Vec<u8>;
+}
+
+/// Passes bytes through unchanged. The default codec when no obfuscation or
+/// encryption is needed.
+#[derive(Default)]
+pub struct PlainCodec;
+
+impl Codec for PlainCodec {
+    fn encode(&mut self, data: &[u8]) -> Vec<u8> {
+        data.to_vec()
+    }
+
+    fn decode(&mut self, data: &[u8]) -> Vec<u8> {
+        data.to_vec()
+    }
+}
+
+/// XORs data against a repeating keystream derived from a user-supplied key.
+///
+/// The keystream position is carried across calls rather than restarting at
+/// the start of the key for each call. The position wraps modulo the key
+/// length, so the same plaintext encoded twice in a row still gives the same
+/// output whenever its length is a multiple of the key length.
+pub struct XorCodec {
+    key: Vec<u8>,
+    position: usize,
+}
+
+impl XorCodec {
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        let key = key.into();
+        assert!(!key.is_empty(), "XOR codec key must not be empty");
+
+        Self { key, position: 0 }
+    }
+
+    fn apply(&mut self, data: &[u8]) -> Vec<u8> {
+        let out = data
+            .iter()
+            .enumerate()
+            .map(|(i, byte)| byte ^ self.key[(self.position + i) % self.key.len()])
+            .collect();
+
+        self.position = (self.position + data.len()) % self.key.len();
+
+        out
+    }
+}
+
+impl Codec for XorCodec {
+    fn encode(&mut self, data: &[u8]) -> Vec<u8> {
+        self.apply(data)
+    }
+
+    fn decode(&mut self, data: &[u8]) -> Vec<u8> {
+        // XOR is its own inverse, but `apply` still needs to run so the
+        // keystream position stays in lockstep with the encoding side.
+        self.apply(data)
+    }
+}
@@ -1,14 +1,18 @@
-use std::sync::{Arc, mpsc, Mutex};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::VecDeque;
+use std::sync::{Arc, mpsc, Condvar, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
 use std::sync::mpsc::TryRecvError;
 use std::thread;
 use std::thread::{JoinHandle, sleep};
 use std::time::Duration;
+use crossbeam_channel::{bounded, Receiver as ScanReceiver, Select, SendTimeoutError};
 use log::error;
-use threadpool::ThreadPool;
+use rand::Rng;
+use crate::codec::Codec;
 use crate::dsp::generate_wave;
 use crate::stream::{RxStream, TxStream};
 
+pub mod codec;
 pub mod dsp;
 pub mod graphy;
 pub mod radio;
@@ -28,23 +32,258 @@ pub fn frequency_range(start_frequency: f64, stop_frequency: f64) -> FrequencyRa
     }
 }
 
-/// Accumulates binary information and outputs it on a channel once it is complete
+/// CRC-16-CCITT (poly 0x1021, init 0xFFFF) computed MSB-first over `data`.
+///
+/// Used to detect bit flips introduced by the noisy OOK channel before a
+/// frame's payload is forwarded to a caller.
+fn crc16_ccitt(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+
+        for _ in 0..8 {
+            if crc & 0x8000 != 0 {
+                crc = (crc << 1) ^ 0x1021;
+            } else {
+                crc <<= 1;
+            }
+        }
+    }
+
+    crc
+}
+
+// Must evenly divide 256 so that `seq % WINDOW_SIZE` never aliases two
+// sequence numbers that are simultaneously in flight within the window.
+const WINDOW_SIZE: usize = 16;
+
+// Caps how many completed frames can sit unread before the reader applies
+// its overflow policy.
+const FRAME_CHANNEL_CAPACITY: usize = 32;
+
+// Once more than this many decoded bytes are buffered, the capture thread stops
+// pulling new samples off the stream until the consumer drains some.
+const BYTE_BUDGET: usize = 64 * 1024;
+
+/// What to do when a completed frame arrives and `FRAME_CHANNEL_CAPACITY` is full.
+#[derive(Clone, Copy, PartialEq)]
+pub enum OverflowPolicy {
+    /// Block the decode thread until the consumer drains a frame.
+    Block,
+    /// Drop the oldest buffered frame so latency-sensitive callers always see fresh data.
+    DropOldest,
+}
+
+/// A frame queue bounded both by frame count (`FRAME_CHANNEL_CAPACITY`) and, via
+/// `bytes_queued`, by total buffered bytes, so a burst of traffic can't grow
+/// memory without bound while `RadioReader::read` goes uncalled.
+struct BoundedFrameChannel {
+    queue: Mutex<VecDeque<Vec<u8>>>,
+    not_full: Condvar,
+    capacity: usize,
+    policy: OverflowPolicy,
+    bytes_queued: AtomicUsize,
+    run: Arc<AtomicBool>,
+}
+
+impl BoundedFrameChannel {
+    fn new(capacity: usize, policy: OverflowPolicy, run: Arc<AtomicBool>) -> Self {
+        Self {
+            queue: Mutex::new(VecDeque::with_capacity(capacity)),
+            not_full: Condvar::new(),
+            capacity,
+            policy,
+            bytes_queued: AtomicUsize::new(0),
+            run,
+        }
+    }
+
+    fn push(&self, frame: Vec<u8>) {
+        let mut queue = self.queue.lock().unwrap();
+
+        match self.policy {
+            OverflowPolicy::Block => {
+                // Plain `wait` would block here forever if the consumer stops
+                // calling `read` and nothing else ever notifies `not_full` --
+                // this is the capture thread itself, so it would never reach
+                // the `run` check in `spawn_decode_pipeline`'s loop, and
+                // `Drop`'s `handle.join()` would hang waiting on it. Waking up
+                // periodically to recheck `run` gives shutdown a way in.
+                while queue.len() >= self.capacity && self.run.load(Ordering::SeqCst) {
+                    let (guard, _) = self.not_full.wait_timeout(queue, Duration::from_millis(THREAD_SLEEP_MILLIS)).unwrap();
+                    queue = guard;
+                }
+
+                if !self.run.load(Ordering::SeqCst) {
+                    return;
+                }
+            }
+            OverflowPolicy::DropOldest => {
+                if queue.len() >= self.capacity {
+                    if let Some(dropped) = queue.pop_front() {
+                        self.bytes_queued.fetch_sub(dropped.len(), Ordering::SeqCst);
+                    }
+                }
+            }
+        }
+
+        self.bytes_queued.fetch_add(frame.len(), Ordering::SeqCst);
+        queue.push_back(frame);
+    }
+
+    fn try_recv(&self) -> Option<Vec<u8>> {
+        let frame = self.queue.lock().unwrap().pop_front();
+
+        if let Some(ref frame) = frame {
+            self.bytes_queued.fetch_sub(frame.len(), Ordering::SeqCst);
+            self.not_full.notify_one();
+        }
+
+        frame
+    }
+
+    fn bytes_queued(&self) -> usize {
+        self.bytes_queued.load(Ordering::SeqCst)
+    }
+}
+
+/// Reassembles a sequence of fragments (each tagged with a wrapping sequence
+/// number and a "more fragments" flag) back into whole messages, tolerating
+/// frames that arrive out of order.
+///
+/// Frames are parked in a fixed-size ring keyed by `seq % WINDOW_SIZE` until a
+/// contiguous run starting at `next_expected` is available, at which point
+/// they're drained in order and concatenated until a frame without the
+/// more-fragments flag completes the message.
+///
+/// Decoding also happens here rather than when a fragment first completes:
+/// `codec` may be stateful (e.g. a keystream cipher whose position advances
+/// with every call), so it must only ever be driven in the same byte-offset
+/// order the writer encoded it in. `drain` is the one place that's guaranteed,
+/// since frames are pulled out strictly in ascending `next_expected` order.
+///
+/// A fragment that never arrives (dropped by the CRC check, or lost on the
+/// link entirely) would otherwise leave a permanent hole at `next_expected`,
+/// wedging every later message behind it. Ordinary reordering fills the rest
+/// of the window without `next_expected` budging, so that alone can't mean
+/// the fragment is lost; only once fragments keep arriving from *ahead* of
+/// the window -- meaning it's full and `next_expected` still hasn't shown up
+/// -- across `MAX_STALL_INSERTS` such arrivals is the slot given up on and
+/// skipped. Stale/duplicate fragments (behind the window, not ahead of it)
+/// don't count toward this, since they say nothing about whether the head of
+/// the window is actually stuck.
+struct ReassemblyWindow {
+    slots: VecDeque<Option<(bool, Vec<u8>)>>,
+    next_expected: u8,
+    message: Vec<u8>,
+    codec: Box<dyn Codec>,
+    sink: Box<dyn Fn(Vec<u8>) + Send>,
+    stalled_inserts: u32,
+}
+
+/// How many fragments may arrive while the window is full and `next_expected`
+/// is still missing before that slot is given up on and skipped.
+const MAX_STALL_INSERTS: u32 = 3;
+
+impl ReassemblyWindow {
+    fn new(codec: Box<dyn Codec>, sink: Box<dyn Fn(Vec<u8>) + Send>) -> Self {
+        Self {
+            slots: VecDeque::from(vec![None; WINDOW_SIZE]),
+            next_expected: 0,
+            message: Vec::new(),
+            codec,
+            sink,
+            stalled_inserts: 0,
+        }
+    }
+
+    fn insert(&mut self, seq: u8, more_fragments: bool, payload: Vec<u8>) {
+        // Distance from the front of the window; anything outside it is too
+        // old (already delivered) or too far ahead (reader fell behind).
+        //
+        // Such a frame is dropped without reaching `codec`, with one
+        // exception: the "ahead" arrival that triggers `skip_stalled_slot`
+        // is inserted again against the advanced window, and is kept (and
+        // later decoded) if it now falls inside it.
+        let distance = seq.wrapping_sub(self.next_expected) as usize;
+        if distance >= WINDOW_SIZE {
+            // `distance` wraps around for stale/duplicate fragments (behind
+            // `next_expected`) the same way it does for fragments genuinely
+            // ahead of the window, landing them both in this branch. Only the
+            // "ahead" half actually indicates the window is stuck; a stale
+            // fragment arriving doesn't mean next_expected is any less stuck
+            // or any more stuck than it already was.
+            if distance < 128 {
+                self.stalled_inserts += 1;
+                if self.stalled_inserts >= MAX_STALL_INSERTS {
+                    self.skip_stalled_slot();
+                    self.insert(seq, more_fragments, payload);
+                }
+            }
+            return;
+        }
+
+        self.slots[seq as usize % WINDOW_SIZE] = Some((more_fragments, payload));
+        self.stalled_inserts = 0;
+
+        self.drain()
+    }
+
+    /// Gives up on the fragment stuck at `next_expected`: fragments have kept
+    /// arriving from ahead of it for too long, so discard whatever message
+    /// was in progress and resync past the gap instead of blocking forever.
+    /// Its bytes are gone for good, so a stateful `codec` keystream is now
+    /// offset by however much data it carried -- the same unavoidable cost
+    /// paid whenever the link drops a fragment outright.
+    ///
+    /// Advancing past the gap can uncover a run of fragments that were
+    /// already buffered ahead of it, so drain immediately rather than
+    /// waiting for the next `insert` to notice.
+    fn skip_stalled_slot(&mut self) {
+        self.slots[self.next_expected as usize % WINDOW_SIZE] = None;
+        self.message.clear();
+        self.next_expected = self.next_expected.wrapping_add(1);
+        self.stalled_inserts = 0;
+        self.drain();
+    }
+
+    fn drain(&mut self) {
+        while let Some((more_fragments, payload)) = self.slots[self.next_expected as usize % WINDOW_SIZE].take() {
+            let decoded = self.codec.decode(&payload);
+            self.message.extend_from_slice(&decoded);
+            self.next_expected = self.next_expected.wrapping_add(1);
+
+            if !more_fragments {
+                (self.sink)(std::mem::take(&mut self.message));
+            }
+        }
+    }
+}
+
+/// Accumulates binary information and hands completed frames to a `ReassemblyWindow`
 struct ByteAccumulator {
+    seq: Option<u8>,
+    more_fragments: bool,
     data_len: usize,
     accum: Vec<u8>,
-    channel: mpsc::Sender<Vec<u8>>,
+    window: Arc<Mutex<ReassemblyWindow>>,
     current_byte: u8,
     current_byte_idx: u8,
+    corrupt_frames: Arc<AtomicUsize>,
 }
 
 impl ByteAccumulator {
-    fn new(channel: mpsc::Sender<Vec<u8>>) -> Self {
+    fn new(window: Arc<Mutex<ReassemblyWindow>>, corrupt_frames: Arc<AtomicUsize>) -> Self {
         Self {
+            seq: None,
+            more_fragments: false,
             data_len: 0,
             accum: vec![],
-            channel,
+            window,
             current_byte: 0,
             current_byte_idx: 0,
+            corrupt_frames,
         }
     }
 
@@ -63,18 +302,40 @@ impl ByteAccumulator {
     }
 
     fn accumulate_byte(&mut self, byte: u8) -> anyhow::Result<()> {
+        // If there's no sequence number configured, this is the first byte of the frame
+        if self.seq.is_none() {
+            self.seq = Some(byte);
+            return Ok(());
+        }
+
         // If there's no data length configured, configure it now
         if self.data_len == 0 {
-            self.data_len = (byte >> 1) as usize;
+            self.more_fragments = byte & 1 == 1;
+            // Account for the 2 trailing CRC-16 bytes appended after the payload
+            self.data_len = (byte >> 1) as usize + 2;
             return Ok(());
         }
 
         self.accum.push(byte);
 
         if self.accum.len() == self.data_len {
-            self.channel.send(self.accum.clone())?;
+            let payload_len = self.data_len - 2;
+            let payload = &self.accum[..payload_len];
+            let received_crc = u16::from_be_bytes([self.accum[payload_len], self.accum[payload_len + 1]]);
+
+            if crc16_ccitt(payload) == received_crc {
+                let seq = self.seq.unwrap();
+                // Decoding happens in `ReassemblyWindow::drain`, not here: a
+                // stateful codec must only ever see byte offsets in sequence
+                // order, and `drain` is what enforces that ordering.
+                self.window.lock().unwrap().insert(seq, self.more_fragments, payload.to_vec());
+            } else {
+                self.corrupt_frames.fetch_add(1, Ordering::SeqCst);
+            }
+
             self.accum.clear();
             self.data_len = 0;
+            self.seq = None;
         }
 
         Ok(())
@@ -85,123 +346,325 @@ impl ByteAccumulator {
 const MAX_BYTES: usize = 127;
 const THREAD_SLEEP_MILLIS: u64 = 50;
 const PULSE_SLEEP_MICROS: u64 = 900;
-const READER_WORKERS: usize = 10;
 
 pub struct RadioReader {
     run: Arc<AtomicBool>,
     handle: Option<JoinHandle<()>>,
-    channel: mpsc::Receiver<Vec<u8>>,
+    channel: Arc<BoundedFrameChannel>,
+    corrupt_frames: Arc<AtomicUsize>,
+    busy: Arc<AtomicBool>,
 }
 
 impl RadioReader {
-    pub fn new(mut stream: RxStream) -> Self {
+    pub fn new(stream: RxStream, sample_rate: f64, codec: Box<dyn Codec>, overflow_policy: OverflowPolicy) -> Self {
         let run = Arc::new(AtomicBool::new(true));
-        let (tx, channel) = mpsc::channel::<Vec<u8>>();
-
-        let run_thread = run.clone();
-        let pool = ThreadPool::new(READER_WORKERS);
-        let handle = thread::spawn(move || while run_thread.load(Ordering::SeqCst) {
-            // Get last set of data
-            // TODO: Check that this is actually needed?
-            for _ in 0..100 {
-                stream.rx();
+        let channel = Arc::new(BoundedFrameChannel::new(FRAME_CHANNEL_CAPACITY, overflow_policy, run.clone()));
+        let corrupt_frames = Arc::new(AtomicUsize::new(0));
+        let busy = Arc::new(AtomicBool::new(false));
+        let sink_channel = channel.clone();
+        let window = Arc::new(Mutex::new(ReassemblyWindow::new(codec, Box::new(move |frame| sink_channel.push(frame)))));
+
+        let throttle_channel = channel.clone();
+        let throttle_run = run.clone();
+        let handle = spawn_decode_pipeline(stream, run.clone(), window, corrupt_frames.clone(), busy.clone(), sample_rate, move || {
+            // Stop pulling new samples until the consumer drains the backlog,
+            // but keep checking `run` so a caller that drops us mid-throttle
+            // isn't stuck here forever -- otherwise `Drop`'s `handle.join()`
+            // would deadlock waiting on a capture thread that never wakes up.
+            while throttle_channel.bytes_queued() > BYTE_BUDGET && throttle_run.load(Ordering::SeqCst) {
+                sleep(Duration::from_millis(THREAD_SLEEP_MILLIS));
             }
+        });
+
+        Self {
+            run,
+            handle: Some(handle),
+            channel,
+            corrupt_frames,
+            busy,
+        }
+    }
 
-            let mut arr = stream.rx();
+    pub fn read(&self) -> Option<Vec<u8>> {
+        self.channel.try_recv()
+    }
 
-            stream.clear_buffer();
+    /// Number of frames dropped so far due to a CRC-16 mismatch, useful for observing link quality.
+    pub fn corrupt_frames(&self) -> usize {
+        self.corrupt_frames.load(Ordering::SeqCst)
+    }
 
-            let mut accum = ByteAccumulator::new(tx.clone());
-            pool.execute(move || {
-                // prepare date
-                let mut avg_over_time = Vec::new();
-                let mut to_avg = Vec::new();
-                let avg_length = 1000;
-                let mut to_avg_num = 0.0;
+    /// Whether the demodulator's averaged amplitude currently sits above its
+    /// adaptive threshold, i.e. whether another transmitter appears to be
+    /// using the channel.
+    pub fn channel_busy(&self) -> bool {
+        self.busy.load(Ordering::SeqCst)
+    }
+}
 
-                // Average the amplitudes
-                for x in 0..arr.len() - 1 {
-                    to_avg.push(dsp::amplitude(*arr.get(x).unwrap()));
+/// Runs the RX-and-decode loop shared by `RadioReader` and `RadioScanner`: pulls
+/// buffers off `stream` and demodulates each one into bits, feeding them to a
+/// fresh `ByteAccumulator`, until `run` is cleared.
+/// `throttle` is invoked before every pull and should block for as long as the
+/// caller wants to delay drawing in more samples (e.g. to apply backpressure).
+/// `busy` is updated each cycle from the demodulator's adaptive threshold, so
+/// callers can carrier-sense the channel before transmitting.
+///
+/// Demodulation happens on this single thread rather than farmed out to a
+/// pool: `OokDemodulator` tracks phase and threshold state across buffers, so
+/// it only makes sense when buffers are fed to it in capture order. A pool
+/// would give no such guarantee -- whichever worker grabs the lock first runs
+/// first, regardless of which buffer came in earlier.
+fn spawn_decode_pipeline(
+    mut stream: RxStream,
+    run: Arc<AtomicBool>,
+    window: Arc<Mutex<ReassemblyWindow>>,
+    corrupt_frames: Arc<AtomicUsize>,
+    busy: Arc<AtomicBool>,
+    sample_rate: f64,
+    throttle: impl Fn() + Send + 'static,
+) -> JoinHandle<()> {
+    let samples_per_symbol = sample_rate * (PULSE_SLEEP_MICROS as f64 / 1_000_000.0);
+    let mut demod = OokDemodulator::new(samples_per_symbol);
+
+    thread::spawn(move || while run.load(Ordering::SeqCst) {
+        throttle();
+
+        // Get last set of data
+        // TODO: Check that this is actually needed?
+        for _ in 0..100 {
+            stream.rx();
+        }
 
-                    if x > avg_length {
-                        let mut num = 0.0;
+        let arr = stream.rx();
 
-                        // add data to be averaged
-                        for y in to_avg.clone() {
-                            num += 300.0 * y;
-                        }
+        stream.clear_buffer();
 
-                        avg_over_time.push(num / avg_length as f32);
+        let mut accum = ByteAccumulator::new(window.clone(), corrupt_frames.clone());
+        let amplitudes: Vec<f32> = (0..arr.len()).map(|x| dsp::amplitude(*arr.get(x).unwrap())).collect();
 
-                        to_avg.remove(0);
-                    }
-                }
+        busy.store(demod.is_busy(&amplitudes), Ordering::SeqCst);
+        demod.process(&amplitudes, &mut accum).unwrap();
+    })
+}
 
-                // calculate the average of the averages
-                for x in avg_over_time.clone() {
-                    to_avg_num += x;
-                }
-                let total_avg = to_avg_num / avg_over_time.len() as f32;
+// Exponential-average gains for tracking the noise floor and mark (on-pulse)
+// amplitude; small values so single noisy samples don't swing the threshold.
+const NOISE_FLOOR_GAIN: f32 = 0.01;
+const MARK_LEVEL_GAIN: f32 = 0.1;
+// How strongly an off-center edge nudges the tracked symbol phase; low enough
+// that drift is absorbed gradually instead of causing a hard re-lock.
+const EDGE_TRACKING_GAIN: f64 = 0.25;
+
+/// An on-off-keying demodulator that self-adjusts its decision threshold to the
+/// midpoint between the tracked noise floor and mark amplitude, and recovers
+/// symbol timing by locking onto the first pulse edge and then slicing the
+/// amplitude envelope every `samples_per_symbol`, correcting its phase estimate
+/// whenever a real edge lands off-center. Persists across buffers so the link
+/// survives clock drift and amplitude changes instead of relying on a fixed
+/// samples-per-bit constant.
+struct OokDemodulator {
+    samples_per_symbol: f64,
+    /// Offset, in samples from the start of the next buffer, of the next symbol slice.
+    phase: f64,
+    locked: bool,
+    noise_floor: f32,
+    mark_level: f32,
+}
 
-                // drop averages down closer to zero and remove data that is below the average
-                for x in 0..avg_over_time.len() {
-                    let mut i = (*avg_over_time.get(x).unwrap()) - total_avg;
+impl OokDemodulator {
+    fn new(samples_per_symbol: f64) -> Self {
+        Self {
+            samples_per_symbol,
+            phase: 0.0,
+            locked: false,
+            noise_floor: 0.0,
+            mark_level: 0.0,
+        }
+    }
 
-                    i *= (i > 0.0) as i32 as f32;
+    fn threshold(&self) -> f32 {
+        (self.noise_floor + self.mark_level) / 2.0
+    }
 
-                    avg_over_time[x] = i;
+    /// Whether the buffer just observed looks like it's carrying a signal,
+    /// i.e. its average amplitude sits above the current decision threshold.
+    fn is_busy(&self, amplitudes: &[f32]) -> bool {
+        if amplitudes.is_empty() {
+            return false;
+        }
+
+        let mean = amplitudes.iter().sum::<f32>() / amplitudes.len() as f32;
+        mean > self.threshold()
+    }
+
+    fn process(&mut self, amplitudes: &[f32], accum: &mut ByteAccumulator) -> anyhow::Result<()> {
+        if amplitudes.is_empty() {
+            return Ok(());
+        }
+
+        for &amplitude in amplitudes {
+            if amplitude > self.threshold() {
+                self.mark_level += (amplitude - self.mark_level) * MARK_LEVEL_GAIN;
+            } else {
+                self.noise_floor += (amplitude - self.noise_floor) * NOISE_FLOOR_GAIN;
+            }
+        }
+
+        let threshold = self.threshold();
+
+        if !self.locked {
+            let edge = amplitudes.windows(2).position(|w| w[0] <= threshold && w[1] > threshold);
+
+            let Some(edge) = edge else {
+                return Ok(());
+            };
+
+            self.phase = edge as f64;
+            self.locked = true;
+        }
+
+        let search_radius = (self.samples_per_symbol / 4.0).max(1.0) as usize;
+
+        while self.phase < amplitudes.len() as f64 {
+            let index = self.phase.round() as usize;
+
+            if index >= amplitudes.len() {
+                break;
+            }
+
+            accum.accumulate_bit(amplitudes[index] > threshold)?;
+
+            // If a real edge lands near this slice point but off-center, nudge
+            // the tracked phase toward it rather than hard re-locking.
+            let start = index.saturating_sub(search_radius);
+            let end = (index + search_radius).min(amplitudes.len().saturating_sub(1));
+
+            if end > start {
+                let nearby_edge = amplitudes[start..end]
+                    .windows(2)
+                    .position(|w| (w[0] > threshold) != (w[1] > threshold));
+
+                if let Some(offset) = nearby_edge {
+                    let actual_edge = (start + offset) as f64;
+                    self.phase += (actual_edge - self.phase) * EDGE_TRACKING_GAIN;
                 }
+            }
 
-                let mut counter = 0;
-                let mut last_counter = 0;
-                let mut bin = "".to_owned();
+            self.phase += self.samples_per_symbol;
+        }
 
-                while counter < avg_over_time.len() {
-                    if avg_over_time[counter] > 0.05 {
-                        if counter - last_counter > 10 {
-                            let mut hold = (counter - last_counter) as i32;
+        // Carry the remaining phase over into the next buffer.
+        self.phase -= amplitudes.len() as f64;
 
-                            hold -= 3300;
+        Ok(())
+    }
+}
 
-                            while hold > 0 {
-                                accum.accumulate_bit(false).unwrap();
-                                bin.push('0');
-                                hold -= 3300;
-                            }
+impl Drop for RadioReader {
+    fn drop(&mut self) {
+        self.run.store(false, Ordering::SeqCst);
+        self.handle.take().unwrap().join().unwrap();
+    }
+}
+
+/// Monitors several `FrequencyRange`s at once, one decode pipeline per range, and
+/// lets a caller block on whichever one completes a frame first via `read_any`
+/// instead of busy-polling each range with `try_recv`.
+///
+/// `ranges` and `streams` are paired positionally: `streams[i]` must already be
+/// tuned to `ranges[i]` (e.g. built from [`frequency_range`]).
+pub struct RadioScanner {
+    run: Arc<AtomicBool>,
+    handles: Vec<JoinHandle<()>>,
+    ranges: Vec<FrequencyRange>,
+    receivers: Vec<ScanReceiver<Vec<u8>>>,
+    corrupt_frames: Vec<Arc<AtomicUsize>>,
+}
+
+impl RadioScanner {
+    pub fn new(ranges: Vec<FrequencyRange>, streams: Vec<RxStream>, sample_rate: f64, codecs: Vec<Box<dyn Codec>>) -> Self {
+        assert_eq!(ranges.len(), streams.len(), "one stream is required per frequency range");
+        assert_eq!(ranges.len(), codecs.len(), "one codec is required per frequency range");
 
-                            accum.accumulate_bit(true).unwrap();
-                            bin.push('1');
+        let run = Arc::new(AtomicBool::new(true));
+        let mut handles = Vec::with_capacity(streams.len());
+        let mut receivers = Vec::with_capacity(streams.len());
+        let mut corrupt_frames = Vec::with_capacity(streams.len());
+
+        for (stream, codec) in streams.into_iter().zip(codecs) {
+            let (tx, rx) = bounded::<Vec<u8>>(FRAME_CHANNEL_CAPACITY);
+            let range_corrupt_frames = Arc::new(AtomicUsize::new(0));
+            let sink_run = run.clone();
+            let window = Arc::new(Mutex::new(ReassemblyWindow::new(codec, Box::new(move |frame| {
+                // A plain blocking `send` would stall this range's capture
+                // thread (which also does the decoding) forever if the caller
+                // stops calling `read_any` -- and `Drop`'s `join` would then
+                // hang waiting on it. Wake up periodically to
+                // recheck `run` and give up on the frame once shutdown starts.
+                let mut frame = frame;
+                loop {
+                    match tx.send_timeout(frame, Duration::from_millis(THREAD_SLEEP_MILLIS)) {
+                        Ok(()) => break,
+                        Err(SendTimeoutError::Timeout(returned)) => {
+                            if !sink_run.load(Ordering::SeqCst) {
+                                break;
+                            }
+                            frame = returned;
                         }
-                        last_counter = counter;
+                        Err(SendTimeoutError::Disconnected(_)) => break,
                     }
-
-                    counter += 1;
                 }
-            });
-        });
+            }))));
+
+            let busy = Arc::new(AtomicBool::new(false));
+            let handle = spawn_decode_pipeline(stream, run.clone(), window, range_corrupt_frames.clone(), busy, sample_rate, || {});
+
+            handles.push(handle);
+            receivers.push(rx);
+            corrupt_frames.push(range_corrupt_frames);
+        }
 
         Self {
             run,
-            handle: Some(handle),
-            channel,
+            handles,
+            ranges,
+            receivers,
+            corrupt_frames,
         }
     }
 
-    pub fn read(&self) -> Option<Vec<u8>> {
-        match self.channel.try_recv() {
-            Ok(vec) => Some(vec),
-            Err(e) => match e {
-                TryRecvError::Empty => None,
-                TryRecvError::Disconnected => panic!("Receive channel disconnected!"),
-            }
+    /// Blocks until any monitored range completes a frame, returning its index into
+    /// the `ranges`/`streams` slices passed to `new` along with the reassembled bytes.
+    pub fn read_any(&self) -> (usize, Vec<u8>) {
+        let mut select = Select::new();
+        for receiver in &self.receivers {
+            select.recv(receiver);
         }
+
+        let op = select.select();
+        let index = op.index();
+        let frame = op.recv(&self.receivers[index]).expect("decode pipeline disconnected");
+
+        (index, frame)
+    }
+
+    pub fn range(&self, index: usize) -> &FrequencyRange {
+        &self.ranges[index]
+    }
+
+    /// Number of frames dropped so far on the range at `index` due to a CRC-16 mismatch.
+    pub fn corrupt_frames(&self, index: usize) -> usize {
+        self.corrupt_frames[index].load(Ordering::SeqCst)
     }
 }
 
-impl Drop for RadioReader {
+impl Drop for RadioScanner {
     fn drop(&mut self) {
         self.run.store(false, Ordering::SeqCst);
-        self.handle.take().unwrap().join().unwrap();
+        for handle in self.handles.drain(..) {
+            handle.join().unwrap();
+        }
     }
 }
 
@@ -209,10 +672,12 @@ pub struct RadioWriter {
     handle: Option<JoinHandle<()>>,
     /// Ensures that the sender is only ever accessed from one thread at a time
     channel: Mutex<mpsc::Sender<u8>>,
+    next_seq: AtomicU8,
+    codec: Box<dyn Codec>,
 }
 
 impl RadioWriter {
-    pub fn new(mut stream: TxStream, frequency: f64, sample_rate: f64, num_samples: i32) -> Self {
+    pub fn new(mut stream: TxStream, frequency: f64, sample_rate: f64, num_samples: i32, codec: Box<dyn Codec>) -> Self {
         let (channel, rx) = mpsc::channel();
         let wave = generate_wave(frequency, sample_rate, num_samples);
         let handle = thread::spawn(move || loop {
@@ -238,18 +703,51 @@ impl RadioWriter {
         Self {
             handle: Some(handle),
             channel: Mutex::new(channel),
+            next_seq: AtomicU8::new(0),
+            codec,
         }
     }
 
+    /// Sends `data` as one or more fragments, each tagged with a wrapping sequence
+    /// number and a more-fragments flag so the reader can reassemble messages
+    /// larger than `MAX_BYTES` and tolerate reordering.
     pub fn write(&mut self, data: &[u8]) -> anyhow::Result<()> {
-        assert!(data.len() <= MAX_BYTES);
+        self.write_with(data, || {})
+    }
 
-        // Starting one bit then length of data stream
+    /// Like `write`, but calls `before_fragment` before every fragment is queued
+    /// for the transmit thread, including the first. Queuing does not wait for
+    /// earlier fragments to finish transmitting, so for a message of several
+    /// fragments these calls all happen before most of it is on air.
+    fn write_with(&mut self, data: &[u8], mut before_fragment: impl FnMut()) -> anyhow::Result<()> {
+        let encoded = self.codec.encode(data);
         let locked_channel = self.channel.lock().unwrap();
-        locked_channel.send((data.len() as u8) << 1 | 1)?;
 
-        for byte in data {
-            locked_channel.send(*byte)?;
+        // An empty message is still a single (empty) fragment
+        let chunks: Vec<&[u8]> = if encoded.is_empty() {
+            vec![&[]]
+        } else {
+            encoded.chunks(MAX_BYTES).collect()
+        };
+
+        for (idx, chunk) in chunks.iter().enumerate() {
+            before_fragment();
+
+            let more_fragments = idx + 1 < chunks.len();
+            let seq = self.next_seq.fetch_add(1, Ordering::SeqCst);
+
+            locked_channel.send(seq)?;
+            locked_channel.send((chunk.len() as u8) << 1 | more_fragments as u8)?;
+
+            for byte in *chunk {
+                locked_channel.send(*byte)?;
+            }
+
+            // Append a CRC-16 over the payload so the reader can detect corruption
+            let crc = crc16_ccitt(chunk);
+            for byte in crc.to_be_bytes() {
+                locked_channel.send(byte)?;
+            }
         }
 
         Ok(())
@@ -262,4 +760,71 @@ impl Drop for RadioWriter {
         drop(self.channel.lock().unwrap());
         self.handle.take().unwrap().join().unwrap();
     }
+}
+
+/// Combines a `RadioReader` and `RadioWriter` sharing one half-duplex radio
+/// front-end. Before transmitting, `write` carrier-senses the channel via the
+/// reader's windowed amplitude average and, if it's busy, backs off for a
+/// random interval (CSMA/CA style) and retries, so two transceivers sharing a
+/// frequency don't clobber each other.
+pub struct RadioTransceiver {
+    reader: RadioReader,
+    writer: RadioWriter,
+    backoff_window: Duration,
+}
+
+impl RadioTransceiver {
+    pub fn new(
+        rx_stream: RxStream,
+        tx_stream: TxStream,
+        frequency: f64,
+        sample_rate: f64,
+        num_samples: i32,
+        rx_codec: Box<dyn Codec>,
+        tx_codec: Box<dyn Codec>,
+        overflow_policy: OverflowPolicy,
+        backoff_window: Duration,
+    ) -> Self {
+        Self {
+            reader: RadioReader::new(rx_stream, sample_rate, rx_codec, overflow_policy),
+            writer: RadioWriter::new(tx_stream, frequency, sample_rate, num_samples, tx_codec),
+            backoff_window,
+        }
+    }
+
+    /// Whether the channel currently appears to be carrying a signal.
+    pub fn channel_busy(&self) -> bool {
+        self.reader.channel_busy()
+    }
+
+    pub fn read(&self) -> Option<Vec<u8>> {
+        self.reader.read()
+    }
+
+    /// Number of frames dropped so far due to a CRC-16 mismatch, useful for observing link quality.
+    pub fn corrupt_frames(&self) -> usize {
+        self.reader.corrupt_frames()
+    }
+
+    /// Sends `data`, deferring transmission with a randomized backoff while the
+    /// channel is busy instead of transmitting blindly over another sender.
+    /// Carrier sense runs before each fragment is queued for the transmit
+    /// thread. Fragments are queued without waiting for earlier ones to go out,
+    /// so a sender that keys up while a message longer than `MAX_BYTES` is
+    /// being transmitted is not detected.
+    pub fn write(&mut self, data: &[u8]) -> anyhow::Result<()> {
+        let reader = &self.reader;
+        let backoff_window = self.backoff_window;
+
+        self.writer.write_with(data, || {
+            while reader.channel_busy() {
+                sleep(random_backoff(backoff_window));
+            }
+        })
+    }
+}
+
+fn random_backoff(window: Duration) -> Duration {
+    let millis = rand::thread_rng().gen_range(0..=window.as_millis().max(1) as u64);
+    Duration::from_millis(millis)
 }
\ No newline at end of file